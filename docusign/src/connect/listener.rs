@@ -0,0 +1,225 @@
+use anyhow::Result;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use subtle::ConstantTimeEq;
+
+use crate::connect_configurations::ConnectConfigurations;
+
+/// The prefix of the headers DocuSign sends HMAC signatures under: the
+/// first configured key's signature is sent as `X-DocuSign-Signature-1`,
+/// additional keys as `X-DocuSign-Signature-2`, `-3`, and so on.
+pub const SIGNATURE_HEADER_PREFIX: &str = "X-DocuSign-Signature-";
+
+/// A Connect message as delivered to the configured listener URL.
+///
+/// DocuSign sends either a JSON or an XML envelope depending on how the
+/// Connect configuration's `urlToPublishTo` payload format is set up.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(untagged)]
+pub enum ConnectMessage {
+    Json(ConnectEvent),
+    /// The raw XML envelope. DocuSign's classic Connect format is XML and
+    /// does not map cleanly onto a single typed struct across all the
+    /// event types it can carry, so callers that need it parse this
+    /// themselves (for example with `quick-xml` or `serde-xml-rs`).
+    Xml(String),
+}
+
+/// The JSON envelope DocuSign Connect posts for aggregate (non-legacy) events.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ConnectEvent {
+    pub event: String,
+    #[serde(default, rename = "apiVersion")]
+    pub api_version: String,
+    #[serde(default)]
+    pub uri: String,
+    #[serde(default, rename = "retryCount")]
+    pub retry_count: i64,
+    #[serde(default, rename = "configurationId")]
+    pub configuration_id: i64,
+    #[serde(default, rename = "generatedDateTime")]
+    pub generated_date_time: String,
+    pub data: ConnectEventData,
+}
+
+/// The `data` payload of a [`ConnectEvent`].
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ConnectEventData {
+    #[serde(default, rename = "accountId")]
+    pub account_id: String,
+    #[serde(default, rename = "userId")]
+    pub user_id: String,
+    #[serde(default, rename = "envelopeId")]
+    pub envelope_id: String,
+    /// The envelope summary DocuSign embeds alongside the event. Its shape
+    /// varies with the account's Connect include settings (recipients,
+    /// custom fields, tabs, ...), so it is left as opaque JSON rather than
+    /// a strongly typed struct.
+    #[serde(default, rename = "envelopeSummary")]
+    pub envelope_summary: serde_json::Value,
+}
+
+/// Parses an inbound Connect message body.
+///
+/// `content_type` should be the request's `Content-Type` header value;
+/// DocuSign sends `application/json` or `text/xml` depending on the
+/// configuration's payload format.
+pub fn parse_connect_message(body: &[u8], content_type: &str) -> Result<ConnectMessage> {
+    if content_type.contains("json") {
+        Ok(ConnectMessage::Json(serde_json::from_slice(body)?))
+    } else {
+        Ok(ConnectMessage::Xml(String::from_utf8(body.to_vec())?))
+    }
+}
+
+/// Computes `Base64(HMAC-SHA256(body, key))`, the digest DocuSign sends in
+/// each `X-DocuSign-Signature-N` header.
+fn compute_signature(body: &[u8], key: &str) -> Result<String> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key.as_bytes())
+        .map_err(|e| anyhow::anyhow!("invalid HMAC key length: {}", e))?;
+    mac.update(body);
+    Ok(base64::encode(mac.finalize().into_bytes()))
+}
+
+/// Verifies a raw Connect request body against the account's configured
+/// HMAC secrets.
+///
+/// `raw_body` must be the exact bytes DocuSign sent, before any
+/// re-serialization -- whitespace and key ordering affect the digest.
+/// `signatures` is the list of `X-DocuSign-Signature-N` header values
+/// present on the request, in any order. `hmac_keys` is the set of secrets
+/// configured on the Connect configuration; DocuSign computes one
+/// signature per configured key, so a request is authentic if it matches
+/// *any* of them.
+///
+/// Returns `true` if at least one signature matches one key. Comparisons
+/// are constant-time to avoid leaking digest bytes through timing.
+pub fn verify_signature(raw_body: &[u8], signatures: &[String], hmac_keys: &[String]) -> bool {
+    let expected: Vec<String> = hmac_keys
+        .iter()
+        .filter_map(|key| compute_signature(raw_body, key).ok())
+        .collect();
+
+    signatures.iter().any(|given| {
+        expected
+            .iter()
+            .any(|want| given.as_bytes().ct_eq(want.as_bytes()).into())
+    })
+}
+
+impl ConnectConfigurations {
+    /// Fetches the Connect configuration for `connect_id` and verifies an
+    /// inbound push request against its configured HMAC secrets in one call.
+    ///
+    /// This is the one-call convenience wrapper the `connect::listener`
+    /// module exists to provide: most callers just want to know "is this
+    /// request really from DocuSign" without manually plumbing the
+    /// configuration lookup through to [`verify_signature`].
+    pub async fn connect_verify_request(
+        &self,
+        account_id: &str,
+        connect_id: &str,
+        raw_body: &[u8],
+        signatures: &[String],
+    ) -> crate::error::Result<bool> {
+        let config = self
+            .connect_get_config_connect_configurations(account_id, connect_id)
+            .await?;
+
+        Ok(verify_signature(raw_body, signatures, &config.hmac_keys))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn verify_signature_accepts_a_matching_digest() {
+        let body = br#"{"event":"envelope-completed"}"#;
+        let key = "top-secret".to_string();
+        let signature = compute_signature(body, &key).unwrap();
+
+        assert!(verify_signature(body, &[signature], &[key]));
+    }
+
+    #[test]
+    fn verify_signature_rejects_a_tampered_body() {
+        let key = "top-secret".to_string();
+        let signature = compute_signature(b"original body", &key).unwrap();
+
+        assert!(!verify_signature(b"tampered body", &[signature], &[key]));
+    }
+
+    #[test]
+    fn verify_signature_rejects_the_wrong_key() {
+        let body = b"some connect payload";
+        let signature = compute_signature(body, &"key-a".to_string()).unwrap();
+
+        assert!(!verify_signature(body, &[signature], &["key-b".to_string()]));
+    }
+
+    #[test]
+    fn verify_signature_matches_any_configured_key() {
+        let body = b"some connect payload";
+        let keys = vec!["key-a".to_string(), "key-b".to_string()];
+        let signature = compute_signature(body, &keys[1]).unwrap();
+
+        // Only the second of two configured keys produced this signature,
+        // which must still count as verified.
+        assert!(verify_signature(body, &[signature], &keys));
+    }
+
+    #[test]
+    fn verify_signature_with_no_keys_configured_never_matches() {
+        let body = b"some connect payload";
+        let signature = compute_signature(body, &"key-a".to_string()).unwrap();
+
+        assert!(!verify_signature(body, &[signature], &[]));
+    }
+
+    #[test]
+    fn verify_signature_with_no_signatures_on_the_request_never_matches() {
+        let body = b"some connect payload";
+
+        assert!(!verify_signature(body, &[], &["key-a".to_string()]));
+    }
+
+    #[test]
+    fn parse_connect_message_reads_json_envelopes() {
+        let body = br#"{
+            "event": "envelope-completed",
+            "apiVersion": "2.1",
+            "uri": "/envelopes/1",
+            "retryCount": 0,
+            "configurationId": 123,
+            "generatedDateTime": "2024-01-01T00:00:00.0000000Z",
+            "data": {
+                "accountId": "acct-1",
+                "userId": "user-1",
+                "envelopeId": "envelope-1",
+                "envelopeSummary": {"status": "completed"}
+            }
+        }"#;
+
+        let message = parse_connect_message(body, "application/json").unwrap();
+        match message {
+            ConnectMessage::Json(event) => {
+                assert_eq!(event.event, "envelope-completed");
+                assert_eq!(event.data.envelope_id, "envelope-1");
+            }
+            ConnectMessage::Xml(_) => panic!("expected a JSON envelope"),
+        }
+    }
+
+    #[test]
+    fn parse_connect_message_reads_xml_envelopes() {
+        let body = b"<DocuSignEnvelopeInformation></DocuSignEnvelopeInformation>";
+
+        let message = parse_connect_message(body, "text/xml").unwrap();
+        match message {
+            ConnectMessage::Xml(xml) => assert!(xml.contains("DocuSignEnvelopeInformation")),
+            ConnectMessage::Json(_) => panic!("expected an XML envelope"),
+        }
+    }
+}
@@ -0,0 +1,8 @@
+//! Support for the real-time push side of DocuSign Connect.
+//!
+//! `crate::connect_configurations` covers the REST operations for managing
+//! Connect definitions (create/read/update/delete). This module covers the
+//! other half: receiving and authenticating the payloads those
+//! configurations push to your webhook endpoint.
+
+pub mod listener;
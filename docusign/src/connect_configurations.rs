@@ -1,11 +1,123 @@
-use anyhow::Result;
+use futures::Stream;
 
+use crate::error::Result;
 use crate::Client;
 
 pub struct ConnectConfigurations {
     pub client: Client,
 }
 
+/// A status a Connect-integrated user can be filtered by.
+///
+/// Serializes via `Display`/`ToString` to the exact strings DocuSign expects
+/// in the `status` query parameter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UserStatus {
+    ActivationRequired,
+    ActivationSent,
+    Active,
+    Closed,
+    Disabled,
+}
+
+impl std::fmt::Display for UserStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            UserStatus::ActivationRequired => "ActivationRequired",
+            UserStatus::ActivationSent => "ActivationSent",
+            UserStatus::Active => "Active",
+            UserStatus::Closed => "Closed",
+            UserStatus::Disabled => "Disabled",
+        })
+    }
+}
+
+/// The query parameters accepted by [`ConnectConfigurations::connect_get_user`].
+///
+/// All fields are optional; only the ones set are sent as query parameters.
+#[derive(Debug, Clone, Default)]
+pub struct ConnectGetUserRequest {
+    pub count: Option<u32>,
+    pub email_substring: Option<String>,
+    pub list_included_users: Option<String>,
+    pub start_position: Option<u32>,
+    pub status: Vec<UserStatus>,
+    pub user_name_substring: Option<String>,
+}
+
+impl ConnectGetUserRequest {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn count(mut self, count: u32) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn email_substring(mut self, email_substring: impl Into<String>) -> Self {
+        self.email_substring = Some(email_substring.into());
+        self
+    }
+
+    pub fn list_included_users(mut self, list_included_users: impl Into<String>) -> Self {
+        self.list_included_users = Some(list_included_users.into());
+        self
+    }
+
+    pub fn start_position(mut self, start_position: u32) -> Self {
+        self.start_position = Some(start_position);
+        self
+    }
+
+    /// Filters the results by user status. DocuSign expects a
+    /// comma-separated list, which this builds from `status` automatically.
+    pub fn status(mut self, status: &[UserStatus]) -> Self {
+        self.status = status.to_vec();
+        self
+    }
+
+    pub fn user_name_substring(mut self, user_name_substring: impl Into<String>) -> Self {
+        self.user_name_substring = Some(user_name_substring.into());
+        self
+    }
+
+    fn query_args(&self) -> Vec<(String, String)> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(count) = self.count {
+            query_args.push(("count".to_string(), count.to_string()));
+        }
+        if let Some(email_substring) = &self.email_substring {
+            query_args.push(("email_substring".to_string(), email_substring.clone()));
+        }
+        if let Some(list_included_users) = &self.list_included_users {
+            query_args.push((
+                "list_included_users".to_string(),
+                list_included_users.clone(),
+            ));
+        }
+        if let Some(start_position) = self.start_position {
+            query_args.push(("start_position".to_string(), start_position.to_string()));
+        }
+        if !self.status.is_empty() {
+            let status = self
+                .status
+                .iter()
+                .map(|s| s.to_string())
+                .collect::<Vec<_>>()
+                .join(",");
+            query_args.push(("status".to_string(), status));
+        }
+        if let Some(user_name_substring) = &self.user_name_substring {
+            query_args.push((
+                "user_name_substring".to_string(),
+                user_name_substring.clone(),
+            ));
+        }
+        query_args
+    }
+}
+
 impl ConnectConfigurations {
     #[doc(hidden)]
     pub fn new(client: Client) -> Self {
@@ -161,61 +273,15 @@ impl ConnectConfigurations {
      *
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      * * `connect_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `count: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `email_substring: &str` -- Filters returned user records by full email address or a substring of email address.
-     * * `list_included_users: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `start_position: &str` -- The position within the total result set from which to start returning values. The value **thumbnail** may be used to return the page image.
-     * * `status: &str` -- Filters the results by user status.
-     *   You can specify a comma-separated
-     *   list of the following statuses:
-     *   
-     *   * ActivationRequired
-     *   * ActivationSent
-     *   * Active
-     *   * Closed
-     *   * Disabled
-     *   .
-     * * `user_name_substring: &str` -- Filters results based on a full or partial user name.
-     *   
-     *   **Note**: When you enter a partial user name, you do not use a wildcard character.
+     * * `request: &ConnectGetUserRequest` -- The optional filters for the list, built with `ConnectGetUserRequest::new()`.
      */
     pub async fn connect_get_user(
         &self,
         account_id: &str,
         connect_id: &str,
-        count: &str,
-        email_substring: &str,
-        list_included_users: &str,
-        start_position: &str,
-        status: &str,
-        user_name_substring: &str,
+        request: &ConnectGetUserRequest,
     ) -> Result<crate::types::IntegratedUserInfoList> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !count.is_empty() {
-            query_args.push(("count".to_string(), count.to_string()));
-        }
-        if !email_substring.is_empty() {
-            query_args.push(("email_substring".to_string(), email_substring.to_string()));
-        }
-        if !list_included_users.is_empty() {
-            query_args.push((
-                "list_included_users".to_string(),
-                list_included_users.to_string(),
-            ));
-        }
-        if !start_position.is_empty() {
-            query_args.push(("start_position".to_string(), start_position.to_string()));
-        }
-        if !status.is_empty() {
-            query_args.push(("status".to_string(), status.to_string()));
-        }
-        if !user_name_substring.is_empty() {
-            query_args.push((
-                "user_name_substring".to_string(),
-                user_name_substring.to_string(),
-            ));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let query_ = serde_urlencoded::to_string(&request.query_args()).unwrap();
         let url = format!(
             "/v2.1/accounts/{}/connect/{}/users?{}",
             crate::progenitor_support::encode_path(&account_id.to_string()),
@@ -225,4 +291,78 @@ impl ConnectConfigurations {
 
         self.client.get(&url, None).await
     }
+
+    /**
+     * Returns users from the configured Connect service, paging through the result set.
+     *
+     * Unlike `billing_get_payment_list_stream`, the Connect users endpoint
+     * has no `nextUri` to follow: it pages by `start_position`/`count`
+     * instead. This re-issues `connect_get_user` with `start_position`
+     * advanced by `count` after each page, stopping once a page comes back
+     * with fewer than `count` users.
+     *
+     * **Parameters:**
+     *
+     * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
+     * * `connect_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
+     * * `count: u32` -- The number of users to request per page. Must be greater than zero.
+     * * `request: ConnectGetUserRequest` -- Additional filters for the list; its `count` and `start_position` are overwritten as the stream pages.
+     */
+    pub fn connect_get_user_stream<'a>(
+        &'a self,
+        account_id: &'a str,
+        connect_id: &'a str,
+        count: u32,
+        request: ConnectGetUserRequest,
+    ) -> impl Stream<Item = Result<crate::types::IntegratedUserInfo>> + 'a {
+        async_stream::try_stream! {
+            if count == 0 {
+                // `start_position` only ever advances by `count`, so a
+                // count of zero would re-request the same page forever.
+                Err(anyhow::anyhow!("connect_get_user_stream: count must be greater than zero"))?;
+            }
+
+            let mut start_position = 0u32;
+
+            loop {
+                let page_request = request
+                    .clone()
+                    .count(count)
+                    .start_position(start_position);
+                let page = self
+                    .connect_get_user(account_id, connect_id, &page_request)
+                    .await?;
+
+                let returned = page.users.len();
+                for user in page.users {
+                    yield user;
+                }
+
+                if returned < count as usize {
+                    break;
+                }
+                start_position += count;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::StreamExt;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn connect_get_user_stream_rejects_a_zero_count() {
+        let client = Client::new("https://example.docusign.net", "token");
+        let connect = client.connect_configurations();
+
+        let stream =
+            connect.connect_get_user_stream("account-1", "connect-1", 0, ConnectGetUserRequest::new());
+        futures::pin_mut!(stream);
+
+        let first = stream.next().await.expect("stream should yield an error");
+        assert!(first.is_err(), "expected a rejection, got {first:?}");
+    }
 }
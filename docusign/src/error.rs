@@ -0,0 +1,74 @@
+//! The typed error returned by client methods.
+//!
+//! Every generated method used to return `anyhow::Result<T>`, which made it
+//! impossible for callers to programmatically distinguish a transport
+//! failure from an expired token, a 429 rate limit, or a DocuSign-reported
+//! validation error without string-matching the message. [`Error`] carries
+//! that information as data instead.
+
+/// The result type returned by [`Client`](crate::Client) methods.
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// The error type returned by [`Client`](crate::Client) methods.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// The HTTP request itself failed (DNS, TLS, connection reset, timeout, ...).
+    #[error("transport error: {0}")]
+    Http(#[from] reqwest::Error),
+
+    /// A request body could not be serialized, or the response body could
+    /// not be deserialized into the expected type.
+    #[error("failed to (de)serialize JSON: {0}")]
+    Json(#[from] serde_json::Error),
+
+    /// DocuSign returned a non-2xx response. `error_code` and `message` come
+    /// from its structured error body when it has one; otherwise
+    /// `error_code` is `None` and `message` is the raw body text.
+    #[error("DocuSign API error ({status}): {message}")]
+    Api {
+        status: reqwest::StatusCode,
+        /// The value of the `X-DocuSign-TraceToken` response header, if present.
+        request_id: Option<String>,
+        /// DocuSign's machine-readable `errorCode`, e.g. `INVALID_REQUEST_PARAMETER`.
+        error_code: Option<String>,
+        message: String,
+    },
+
+    /// Catch-all for failures that don't fit the variants above, so callers
+    /// migrating from the old `anyhow::Result<T>` methods are not broken.
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+/// The `errorCode`/`message` body DocuSign returns alongside non-2xx
+/// statuses, e.g. `{"errorCode": "INVALID_REQUEST_PARAMETER", "message": "..."}`.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ErrorDetails {
+    #[serde(default)]
+    pub error_code: String,
+    #[serde(default)]
+    pub message: String,
+}
+
+impl Error {
+    /// Builds an [`Error::Api`] from a non-2xx response's status, headers,
+    /// and body, parsing the body as DocuSign's `ErrorDetails` shape when
+    /// possible and falling back to the raw body text otherwise.
+    pub fn from_response(status: reqwest::StatusCode, request_id: Option<String>, body: &str) -> Self {
+        match serde_json::from_str::<ErrorDetails>(body) {
+            Ok(details) => Error::Api {
+                status,
+                request_id,
+                error_code: Some(details.error_code),
+                message: details.message,
+            },
+            Err(_) => Error::Api {
+                status,
+                request_id,
+                error_code: None,
+                message: body.to_string(),
+            },
+        }
+    }
+}
@@ -0,0 +1,194 @@
+#![allow(
+    clippy::needless_borrows_for_generic_args,
+    clippy::redundant_clone,
+    clippy::unnecessary_to_owned
+)]
+//! A client for the DocuSign eSignature API.
+//!
+//! Resource types (`Payments`, `ConnectConfigurations`, ...) wrap a
+//! [`Client`] and expose one method per REST operation. Hand-written
+//! extensions on top of the generated surface live alongside them:
+//! webhook verification and the retry policy in their own modules
+//! (`connect`, `retry`), pagination streams next to the list methods
+//! they wrap.
+
+pub mod connect;
+pub mod connect_configurations;
+pub mod error;
+pub mod payments;
+pub mod progenitor_support;
+pub mod retry;
+pub mod types;
+
+pub use connect_configurations::ConnectConfigurations;
+pub use payments::Payments;
+pub use retry::RetryPolicy;
+
+use std::sync::{Arc, Mutex};
+
+use crate::error::{Error, Result};
+use crate::retry::RateLimitInfo;
+
+/// The DocuSign eSignature API client.
+///
+/// Holds the HTTP client, the account's base URL, and the bearer token used
+/// to authenticate requests. Clone is cheap: `reqwest::Client` and the
+/// rate-limit cache are both reference-counted internally.
+#[derive(Debug, Clone)]
+pub struct Client {
+    host: String,
+    token: String,
+    http: reqwest::Client,
+    retry_policy: Option<RetryPolicy>,
+    rate_limit: Arc<Mutex<Option<RateLimitInfo>>>,
+}
+
+impl Client {
+    /// Creates a client pointed at `host` (e.g. `https://na3.docusign.net/restapi`)
+    /// authenticating requests with `token`.
+    pub fn new(host: impl ToString, token: impl ToString) -> Self {
+        Client {
+            host: host.to_string(),
+            token: token.to_string(),
+            http: reqwest::Client::new(),
+            retry_policy: None,
+            rate_limit: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// Opts this client into retrying `429`/`5xx` responses to idempotent
+    /// (`GET`/`DELETE`) requests according to `policy`. Without this, those
+    /// responses are returned to the caller as `Error::Api` immediately, as
+    /// they always were before this existed.
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(policy);
+        self
+    }
+
+    /// The `X-RateLimit-*` values from the most recent response that carried
+    /// all three headers, if any has been received yet. Useful for pacing bulk operations (e.g. posting
+    /// payments across many accounts) before DocuSign starts throttling.
+    pub fn rate_limit(&self) -> Option<RateLimitInfo> {
+        *self.rate_limit.lock().unwrap()
+    }
+
+    pub fn connect_configurations(&self) -> ConnectConfigurations {
+        ConnectConfigurations::new(self.clone())
+    }
+
+    pub fn payments(&self) -> Payments {
+        Payments::new(self.clone())
+    }
+
+    /// Resolves `uri` against `host` unless it is already absolute.
+    /// DocuSign occasionally returns absolute `nextUri`/`previousUri`
+    /// values, so generated methods that forward such a URI straight back
+    /// into `get`/`post`/etc. must not prepend the host twice.
+    fn resolve(&self, uri: &str) -> String {
+        if uri.starts_with("http://") || uri.starts_with("https://") {
+            uri.to_string()
+        } else {
+            format!("{}{}", self.host, uri)
+        }
+    }
+
+    pub async fn get<T: serde::de::DeserializeOwned>(
+        &self,
+        uri: &str,
+        body: Option<reqwest::Body>,
+    ) -> Result<T> {
+        self.execute(reqwest::Method::GET, uri, body).await
+    }
+
+    pub async fn post<T: serde::de::DeserializeOwned>(
+        &self,
+        uri: &str,
+        body: Option<reqwest::Body>,
+    ) -> Result<T> {
+        self.execute(reqwest::Method::POST, uri, body).await
+    }
+
+    pub async fn put<T: serde::de::DeserializeOwned>(
+        &self,
+        uri: &str,
+        body: Option<reqwest::Body>,
+    ) -> Result<T> {
+        self.execute(reqwest::Method::PUT, uri, body).await
+    }
+
+    pub async fn delete<T: serde::de::DeserializeOwned>(
+        &self,
+        uri: &str,
+        body: Option<reqwest::Body>,
+    ) -> Result<T> {
+        self.execute(reqwest::Method::DELETE, uri, body).await
+    }
+
+    /// Issues a request, retrying a `429`/`5xx` on an idempotent
+    /// (`GET`/`DELETE`, bodyless) call per the attached [`RetryPolicy`] if
+    /// any, and turns the eventual non-2xx response into [`Error::Api`],
+    /// populated from DocuSign's `ErrorDetails` body and its
+    /// `X-DocuSign-TraceToken` header.
+    async fn execute<T: serde::de::DeserializeOwned>(
+        &self,
+        method: reqwest::Method,
+        uri: &str,
+        body: Option<reqwest::Body>,
+    ) -> Result<T> {
+        let retryable =
+            matches!(method, reqwest::Method::GET | reqwest::Method::DELETE) && body.is_none();
+        let mut body = body;
+        let mut attempt = 0u32;
+
+        loop {
+            let mut req = self
+                .http
+                .request(method.clone(), self.resolve(uri))
+                .bearer_auth(&self.token);
+            if let Some(body) = body.take() {
+                req = req
+                    .header(reqwest::header::CONTENT_TYPE, "application/json")
+                    .body(body);
+            }
+
+            let resp = req.send().await?;
+            let status = resp.status();
+
+            if let Some(info) = RateLimitInfo::from_headers(resp.headers()) {
+                *self.rate_limit.lock().unwrap() = Some(info);
+            }
+
+            if status.is_success() {
+                let text = resp.text().await?;
+                let text = if text.trim().is_empty() { "null" } else { &text };
+                return Ok(serde_json::from_str(text)?);
+            }
+
+            let throttled_or_unavailable = status.as_u16() == 429 || status.is_server_error();
+            if retryable && throttled_or_unavailable {
+                if let Some(policy) = self.retry_policy.clone() {
+                    if attempt < policy.max_retries {
+                        let delay = if status.as_u16() == 429 {
+                            RateLimitInfo::retry_after(resp.headers())
+                                .unwrap_or_else(|| policy.backoff_delay(attempt))
+                                .min(policy.max_delay)
+                        } else {
+                            policy.backoff_delay(attempt)
+                        };
+                        tokio::time::sleep(delay).await;
+                        attempt += 1;
+                        continue;
+                    }
+                }
+            }
+
+            let request_id = resp
+                .headers()
+                .get("X-DocuSign-TraceToken")
+                .and_then(|v| v.to_str().ok())
+                .map(|s| s.to_string());
+            let text = resp.text().await.unwrap_or_default();
+            return Err(Error::from_response(status, request_id, &text));
+        }
+    }
+}
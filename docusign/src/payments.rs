@@ -1,11 +1,48 @@
-use anyhow::Result;
+use futures::Stream;
 
+use crate::error::Result;
 use crate::Client;
 
 pub struct Payments {
     client: Client,
 }
 
+/// The query parameters accepted by [`Payments::billing_get_payment_list`].
+///
+/// All fields are optional; only the ones set are sent as query parameters.
+#[derive(Debug, Clone, Default)]
+pub struct BillingGetPaymentListRequest {
+    pub from_date: Option<String>,
+    pub to_date: Option<String>,
+}
+
+impl BillingGetPaymentListRequest {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn from_date(mut self, from_date: impl Into<String>) -> Self {
+        self.from_date = Some(from_date.into());
+        self
+    }
+
+    pub fn to_date(mut self, to_date: impl Into<String>) -> Self {
+        self.to_date = Some(to_date.into());
+        self
+    }
+
+    fn query_args(&self) -> Vec<(String, String)> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(from_date) = &self.from_date {
+            query_args.push(("from_date".to_string(), from_date.clone()));
+        }
+        if let Some(to_date) = &self.to_date {
+            query_args.push(("to_date".to_string(), to_date.clone()));
+        }
+        query_args
+    }
+}
+
 impl Payments {
     #[doc(hidden)]
     pub fn new(client: Client) -> Self {
@@ -24,38 +61,63 @@ impl Payments {
      * **Parameters:**
      *
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `from_date: &str` -- Specifies the date/time of the earliest payment in the account to retrieve.
-     * * `to_date: &str` -- Specifies the date/time of the latest payment in the account to retrieve.
+     * * `request: &BillingGetPaymentListRequest` -- The optional date-range filters for the list, built with `BillingGetPaymentListRequest::new()`.
      */
     pub async fn billing_get_payment_list(
         &self,
         account_id: &str,
-        from_date: &str,
-        to_date: &str,
+        request: &BillingGetPaymentListRequest,
     ) -> Result<crate::types::BillingPaymentsResponse> {
-        let mut query = String::new();
-        let mut query_args: Vec<String> = Default::default();
-        if !from_date.is_empty() {
-            query_args.push(format!("from_date={}", from_date));
-        }
-        if !to_date.is_empty() {
-            query_args.push(format!("to_date={}", to_date));
-        }
-        for (i, n) in query_args.iter().enumerate() {
-            if i > 0 {
-                query.push('&');
-            }
-            query.push_str(n);
-        }
+        let query_ = serde_urlencoded::to_string(request.query_args()).unwrap();
         let url = format!(
             "/v2.1/accounts/{}/billing_payments?{}",
-            crate::progenitor_support::encode_path(&account_id.to_string()),
-            query
+            crate::progenitor_support::encode_path(account_id),
+            query_
         );
 
         self.client.get(&url, None).await
     }
 
+    /**
+     * Gets payment information for one or more payments, following `nextUri` across pages.
+     *
+     * This streams the same data as `billing_get_payment_list`, re-issuing
+     * a `GET` against the `nextUri` DocuSign returns on each page until the
+     * field is absent, and yielding one `BillingPaymentItem` at a time.
+     *
+     * **Parameters:**
+     *
+     * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
+     * * `request: &BillingGetPaymentListRequest` -- The optional date-range filters for the list.
+     */
+    pub fn billing_get_payment_list_stream<'a>(
+        &'a self,
+        account_id: &'a str,
+        request: &'a BillingGetPaymentListRequest,
+    ) -> impl Stream<Item = Result<crate::types::BillingPaymentItem>> + 'a {
+        async_stream::try_stream! {
+            let mut page = self.billing_get_payment_list(account_id, request).await?;
+
+            loop {
+                for item in page.payments {
+                    yield item;
+                }
+
+                if page.next_uri.is_empty() {
+                    break;
+                }
+
+                // `next_uri` already carries its own query string, so it
+                // goes straight to `Client::get` rather than through
+                // `billing_get_payment_list`'s query builder. `Client::get`
+                // resolves it against the configured host only if it's not
+                // already absolute, since DocuSign's docs don't guarantee
+                // `nextUri` is always relative.
+                page = self.client.get(&page.next_uri, None).await?;
+            }
+        }
+    }
+
     /**
      * Posts a payment to a past due invoice.
      *
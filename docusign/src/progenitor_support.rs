@@ -0,0 +1,8 @@
+//! Small helpers the generated method bodies rely on, mirroring the
+//! `progenitor`-style generator's runtime support functions.
+
+/// Percent-encodes a single path segment for interpolation into a URL
+/// template, e.g. `{accountId}`.
+pub fn encode_path(pc: &str) -> String {
+    percent_encoding::utf8_percent_encode(pc, percent_encoding::NON_ALPHANUMERIC).to_string()
+}
@@ -0,0 +1,267 @@
+//! Opt-in rate-limit awareness and retry-with-backoff for [`Client`](crate::Client).
+//!
+//! DocuSign enforces hourly request quotas per account and signals them
+//! with a `429` and `X-RateLimit-Limit`/`X-RateLimit-Remaining`/
+//! `X-RateLimit-Reset` response headers. `Client` does not retry by
+//! default -- every call still fails hard on a throttle unless a
+//! [`RetryPolicy`] is attached with [`Client::with_retry_policy`](crate::Client::with_retry_policy).
+//! When one is attached, `Client`'s internal request loop consults it on a
+//! `429` or `5xx` from a retryable (idempotent, bodyless) request before
+//! returning the error to the caller. Whether or not a policy is attached,
+//! the loop records the most recent `X-RateLimit-*` values so callers can
+//! read them back with [`Client::rate_limit`](crate::Client::rate_limit).
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use rand::Rng;
+
+/// How long to wait and how many times to retry a throttled or failed
+/// request.
+///
+/// Backoff for `5xx` responses is exponential with jitter, starting at
+/// `base_delay` and capped at `max_delay`. Backoff for `429` responses
+/// instead honors the server's own `X-RateLimit-Reset`/`Retry-After`
+/// header, also capped at `max_delay`, since DocuSign tells us exactly
+/// when the quota window reopens.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    pub max_retries: u32,
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_retries: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(60),
+        }
+    }
+}
+
+impl RetryPolicy {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    pub fn base_delay(mut self, base_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self
+    }
+
+    pub fn max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// The exponential-backoff-with-jitter delay for `5xx` retry `attempt`
+    /// (0-indexed), capped at `max_delay`.
+    ///
+    /// Uses "full jitter" (a delay drawn uniformly from `[0, capped]`
+    /// rather than a fixed offset derived from `attempt` alone), so that
+    /// many callers retrying the same attempt number concurrently -- e.g.
+    /// a fleet of workers that all got throttled by the same quota reset
+    /// -- spread their retries out instead of retrying in lockstep.
+    pub fn backoff_delay(&self, attempt: u32) -> Duration {
+        let exp = self.base_delay.saturating_mul(1u32 << attempt.min(16));
+        let capped = exp.min(self.max_delay);
+        let bound_ms = capped.as_millis() as u64;
+        let jittered_ms = if bound_ms == 0 {
+            0
+        } else {
+            rand::thread_rng().gen_range(0..=bound_ms)
+        };
+        Duration::from_millis(jittered_ms)
+    }
+}
+
+/// The `X-RateLimit-*` values DocuSign returns on every metered response,
+/// not just `429`s, so callers can pace bulk operations proactively instead
+/// of waiting to get throttled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimitInfo {
+    pub limit: u64,
+    pub remaining: u64,
+    /// The absolute Unix epoch second at which the quota window resets, as
+    /// DocuSign reports it -- not a countdown. This is the same convention
+    /// GitHub's and Twitter's identically-named `X-RateLimit-Reset` headers
+    /// use.
+    pub reset_epoch_seconds: u64,
+}
+
+impl RateLimitInfo {
+    /// Parses the rate-limit headers off a response, if all three are present.
+    pub fn from_headers(headers: &reqwest::header::HeaderMap) -> Option<Self> {
+        let parse = |name: &str| -> Option<u64> { headers.get(name)?.to_str().ok()?.parse().ok() };
+
+        Some(RateLimitInfo {
+            limit: parse("X-RateLimit-Limit")?,
+            remaining: parse("X-RateLimit-Remaining")?,
+            reset_epoch_seconds: parse("X-RateLimit-Reset")?,
+        })
+    }
+
+    /// How long until the window reopens, honoring `Retry-After` if DocuSign
+    /// sent it instead of (or in addition to) `X-RateLimit-Reset`.
+    pub fn retry_after(headers: &reqwest::header::HeaderMap) -> Option<Duration> {
+        Self::retry_after_since(headers, SystemTime::now())
+    }
+
+    /// Same as [`Self::retry_after`], but against an explicit clock reading
+    /// instead of `SystemTime::now()` -- split out so tests can supply a
+    /// fixed "now" rather than racing the wall clock.
+    fn retry_after_since(headers: &reqwest::header::HeaderMap, now: SystemTime) -> Option<Duration> {
+        if let Some(seconds) = headers
+            .get(reqwest::header::RETRY_AFTER)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.parse::<u64>().ok())
+        {
+            return Some(Duration::from_secs(seconds));
+        }
+
+        let info = Self::from_headers(headers)?;
+        let now_epoch_seconds = now.duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+        Some(Duration::from_secs(
+            info.reset_epoch_seconds.saturating_sub(now_epoch_seconds),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff_delay_is_bounded_by_the_exponential_cap() {
+        let policy = RetryPolicy::new()
+            .base_delay(Duration::from_millis(100))
+            .max_delay(Duration::from_secs(60));
+
+        for attempt in 0..6 {
+            let expected_cap = Duration::from_millis(100 * (1u64 << attempt));
+            for _ in 0..20 {
+                let delay = policy.backoff_delay(attempt);
+                assert!(delay <= expected_cap, "attempt {attempt}: {delay:?} > {expected_cap:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn backoff_delay_never_exceeds_max_delay_even_at_high_attempts() {
+        let policy = RetryPolicy::new()
+            .base_delay(Duration::from_millis(500))
+            .max_delay(Duration::from_secs(1));
+
+        for _ in 0..20 {
+            // `1 << 32` would overflow a naive u32 shift; this must stay capped.
+            let delay = policy.backoff_delay(32);
+            assert!(delay <= Duration::from_secs(1));
+        }
+    }
+
+    #[test]
+    fn backoff_delay_spreads_retries_instead_of_lockstep() {
+        let policy = RetryPolicy::new()
+            .base_delay(Duration::from_millis(1000))
+            .max_delay(Duration::from_secs(60));
+
+        // Many callers retrying the same attempt number concurrently should
+        // not all land on the identical delay.
+        let delays: std::collections::HashSet<_> =
+            (0..20).map(|_| policy.backoff_delay(2)).collect();
+        assert!(delays.len() > 1, "expected varied delays, got {delays:?}");
+    }
+
+    #[test]
+    fn rate_limit_info_requires_all_three_headers() {
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert("X-RateLimit-Limit", "1000".parse().unwrap());
+        headers.insert("X-RateLimit-Remaining", "999".parse().unwrap());
+
+        assert!(RateLimitInfo::from_headers(&headers).is_none());
+
+        headers.insert("X-RateLimit-Reset", "1800000000".parse().unwrap());
+        assert_eq!(
+            RateLimitInfo::from_headers(&headers),
+            Some(RateLimitInfo {
+                limit: 1000,
+                remaining: 999,
+                reset_epoch_seconds: 1_800_000_000,
+            })
+        );
+    }
+
+    #[test]
+    fn retry_after_prefers_the_retry_after_header() {
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert("Retry-After", "5".parse().unwrap());
+        headers.insert("X-RateLimit-Reset", "1800000000".parse().unwrap());
+
+        assert_eq!(
+            RateLimitInfo::retry_after_since(&headers, UNIX_EPOCH),
+            Some(Duration::from_secs(5))
+        );
+    }
+
+    #[test]
+    fn retry_after_treats_rate_limit_reset_as_an_absolute_epoch_second() {
+        // `X-RateLimit-Reset` is a real epoch second far in the future
+        // (year 2027), not a seconds-to-wait countdown. Using a value this
+        // large catches the bug where treating it as a countdown would
+        // produce a multi-decade `Duration` that silently gets clamped to
+        // `max_delay` by the caller -- both the small-countdown and
+        // absolute-epoch interpretations must be distinguishable here.
+        let now = UNIX_EPOCH + Duration::from_secs(1_800_000_000);
+        let reset_at = now + Duration::from_secs(120);
+
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert("X-RateLimit-Limit", "1000".parse().unwrap());
+        headers.insert("X-RateLimit-Remaining", "0".parse().unwrap());
+        headers.insert(
+            "X-RateLimit-Reset",
+            reset_at
+                .duration_since(UNIX_EPOCH)
+                .unwrap()
+                .as_secs()
+                .to_string()
+                .parse()
+                .unwrap(),
+        );
+
+        assert_eq!(
+            RateLimitInfo::retry_after_since(&headers, now),
+            Some(Duration::from_secs(120))
+        );
+    }
+
+    #[test]
+    fn retry_after_never_goes_negative_once_the_reset_has_passed() {
+        let now = UNIX_EPOCH + Duration::from_secs(1_800_000_000);
+        let reset_at = now - Duration::from_secs(30);
+
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert("X-RateLimit-Limit", "1000".parse().unwrap());
+        headers.insert("X-RateLimit-Remaining", "0".parse().unwrap());
+        headers.insert(
+            "X-RateLimit-Reset",
+            reset_at
+                .duration_since(UNIX_EPOCH)
+                .unwrap()
+                .as_secs()
+                .to_string()
+                .parse()
+                .unwrap(),
+        );
+
+        assert_eq!(
+            RateLimitInfo::retry_after_since(&headers, now),
+            Some(Duration::from_secs(0))
+        );
+    }
+}
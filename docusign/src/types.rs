@@ -0,0 +1,114 @@
+//! Request/response bodies for the DocuSign eSignature REST API.
+//!
+//! These mirror the shapes DocuSign documents for each operation. Every
+//! field is `#[serde(default)]` so a response that omits a field we don't
+//! use yet still deserializes instead of hard-failing.
+
+/// The body of `GET /v2.1/accounts/{accountId}/connect` and
+/// `GET /v2.1/accounts/{accountId}/connect/{connectId}`.
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConnectConfigResults {
+    #[serde(default)]
+    pub configurations: Vec<ConnectCustomConfiguration>,
+    #[serde(default)]
+    pub total_records: String,
+    /// The account's configured Connect HMAC secrets. DocuSign computes one
+    /// `X-DocuSign-Signature-N` per key configured here, in order.
+    #[serde(default)]
+    pub hmac_keys: Vec<String>,
+}
+
+/// A DocuSign Custom Connect definition, as created/updated/returned by the
+/// `connect_put_configuration`/`connect_post_configuration` operations.
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConnectCustomConfiguration {
+    #[serde(default)]
+    pub connect_id: String,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub url_to_publish_to: String,
+    #[serde(default)]
+    pub all_users: String,
+    #[serde(default)]
+    pub events: Vec<String>,
+    #[serde(default)]
+    pub hmac_keys: Vec<String>,
+}
+
+/// The body of `GET /v2.1/accounts/{accountId}/connect/{connectId}/users`.
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IntegratedUserInfoList {
+    #[serde(default)]
+    pub users: Vec<IntegratedUserInfo>,
+    #[serde(default)]
+    pub total_records: String,
+    #[serde(default)]
+    pub result_set_size: String,
+    #[serde(default)]
+    pub start_position: String,
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IntegratedUserInfo {
+    #[serde(default)]
+    pub user_id: String,
+    #[serde(default)]
+    pub user_name: String,
+    #[serde(default)]
+    pub email: String,
+    #[serde(default)]
+    pub user_status: String,
+}
+
+/// The body of `GET /v2.1/accounts/{accountId}/billing_payments`.
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BillingPaymentsResponse {
+    #[serde(default)]
+    pub payments: Vec<BillingPaymentItem>,
+    /// The relative (or, rarely, absolute) URI for the next page of
+    /// results. Empty when there are no more pages.
+    #[serde(default)]
+    pub next_uri: String,
+    #[serde(default)]
+    pub previous_uri: String,
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BillingPaymentItem {
+    #[serde(default)]
+    pub payment_id: String,
+    #[serde(default)]
+    pub payment_amount: String,
+    #[serde(default)]
+    pub payment_status: String,
+    #[serde(default)]
+    pub created_date: String,
+}
+
+/// The body of `POST /v2.1/accounts/{accountId}/billing_payments`.
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BillingPaymentRequest {
+    #[serde(default)]
+    pub payment_amount: String,
+    #[serde(default)]
+    pub invoice_id: String,
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BillingPaymentResponse {
+    #[serde(default)]
+    pub payments: Vec<BillingPaymentItem>,
+    #[serde(default)]
+    pub next_uri: String,
+    #[serde(default)]
+    pub previous_uri: String,
+}